@@ -6,6 +6,7 @@ use semver::{BuildMetadata, Prerelease, Version};
 pub enum Action {
     SemVerAction(SemVerAction),
     Default,
+    Parse,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -13,6 +14,9 @@ pub enum SemVerAction {
     Major,
     Minor,
     Patch,
+    Alpha,
+    Beta,
+    Rc,
 }
 
 #[derive(Default)]
@@ -20,6 +24,8 @@ pub struct Inc {
     pub error: Option<String>,
     pub cell_path: Option<CellPath>,
     pub action: Option<Action>,
+    pub build: Option<String>,
+    pub recursive: bool,
 }
 
 impl Inc {
@@ -27,27 +33,42 @@ impl Inc {
         Default::default()
     }
 
-    fn apply(&self, input: &str, head: Span) -> Value {
+    fn apply(&self, input: &str, head: Span) -> Result<Value, LabeledError> {
         match &self.action {
             Some(Action::SemVerAction(act_on)) => {
                 let mut ver = match semver::Version::parse(input) {
                     Ok(parsed_ver) => parsed_ver,
-                    Err(_) => return Value::string(input, head),
+                    Err(_) => return Ok(Value::string(input, head)),
                 };
 
                 match act_on {
                     SemVerAction::Major => Self::increment_major(&mut ver),
                     SemVerAction::Minor => Self::increment_minor(&mut ver),
                     SemVerAction::Patch => Self::increment_patch(&mut ver),
+                    SemVerAction::Alpha => Self::increment_pre(&mut ver, "alpha", head)?,
+                    SemVerAction::Beta => Self::increment_pre(&mut ver, "beta", head)?,
+                    SemVerAction::Rc => Self::increment_pre(&mut ver, "rc", head)?,
                 }
 
-                Value::string(ver.to_string(), head)
+                if let Some(build) = &self.build {
+                    ver.build = BuildMetadata::new(build).map_err(|e| LabeledError {
+                        label: "Invalid build metadata".into(),
+                        msg: format!("{build} is not valid build metadata: {e}"),
+                        span: Some(head),
+                    })?;
+                }
+
+                Ok(Value::string(ver.to_string(), head))
             }
+            Some(Action::Parse) => match semver::Version::parse(input) {
+                Ok(parsed_ver) => Ok(Self::parsed_record(&parsed_ver, head)),
+                Err(_) => Ok(Value::string(input, head)),
+            },
             Some(Action::Default) | None => {
                 if let Ok(v) = input.parse::<u64>() {
-                    Value::string((v + 1).to_string(), head)
+                    Ok(Value::string((v + 1).to_string(), head))
                 } else {
-                    Value::string(input, head)
+                    Ok(Value::string(input, head))
                 }
             }
         }
@@ -74,6 +95,45 @@ impl Inc {
         v.build = BuildMetadata::EMPTY;
     }
 
+    pub fn increment_pre(v: &mut Version, phase: &str, head: Span) -> Result<(), LabeledError> {
+        fn phase_rank(phase: &str) -> i8 {
+            match phase {
+                "alpha" => 0,
+                "beta" => 1,
+                "rc" => 2,
+                _ => -1,
+            }
+        }
+
+        if v.pre.is_empty() {
+            // There's no pre-release series to continue, so a pre-release
+            // must precede a version that hasn't been released yet.
+            v.patch += 1;
+            v.pre = Prerelease::new(&format!("{phase}.1")).expect("phase.1 is valid prerelease");
+        } else {
+            let pre = v.pre.as_str();
+            let (current_phase, counter) = pre.split_once('.').unwrap_or((pre, "0"));
+
+            if current_phase == phase {
+                let next = counter.parse::<u64>().unwrap_or(0) + 1;
+                v.pre = Prerelease::new(&format!("{phase}.{next}"))
+                    .expect("phase.N is valid prerelease");
+            } else if phase_rank(phase) > phase_rank(current_phase) {
+                v.pre =
+                    Prerelease::new(&format!("{phase}.1")).expect("phase.1 is valid prerelease");
+            } else {
+                return Err(LabeledError {
+                    label: "Cannot move pre-release phase backwards".into(),
+                    msg: format!("'{current_phase}' is already past '{phase}' in {v}"),
+                    span: Some(head),
+                });
+            }
+        }
+
+        v.build = BuildMetadata::EMPTY;
+        Ok(())
+    }
+
     pub fn for_semver(&mut self, part: SemVerAction) {
         if self.permit() {
             self.action = Some(Action::SemVerAction(part));
@@ -82,6 +142,34 @@ impl Inc {
         }
     }
 
+    pub fn for_parse(&mut self) {
+        if self.permit() {
+            self.action = Some(Action::Parse);
+        } else {
+            self.log_error("can only apply one");
+        }
+    }
+
+    fn parsed_record(ver: &Version, head: Span) -> Value {
+        Value::Record {
+            cols: vec![
+                "major".into(),
+                "minor".into(),
+                "patch".into(),
+                "pre".into(),
+                "build".into(),
+            ],
+            vals: vec![
+                Value::int(ver.major as i64, head),
+                Value::int(ver.minor as i64, head),
+                Value::int(ver.patch as i64, head),
+                Value::string(ver.pre.as_str(), head),
+                Value::string(ver.build.as_str(), head),
+            ],
+            span: head,
+        }
+    }
+
     fn permit(&mut self) -> bool {
         self.action.is_none()
     }
@@ -91,7 +179,7 @@ impl Inc {
     }
 
     pub fn usage() -> &'static str {
-        "Usage: inc field [--major|--minor|--patch]"
+        "Usage: inc field [--major|--minor|--patch|--alpha|--beta|--rc|--parse] [--build string] [--recursive]"
     }
 
     pub fn inc(&self, head: Span, value: &Value) -> Result<Value, LabeledError> {
@@ -109,15 +197,49 @@ impl Inc {
                     error
                 })?;
             Ok(value)
+        } else if self.recursive {
+            self.inc_recursive(head, value)
         } else {
             self.inc_value(head, value)
         }
     }
 
+    pub fn inc_recursive(&self, head: Span, value: &Value) -> Result<Value, LabeledError> {
+        match value {
+            Value::List { vals, span } => {
+                let vals = vals
+                    .iter()
+                    .map(|v| self.inc_recursive(head, v))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Value::List { vals, span: *span })
+            }
+            Value::Record { cols, vals, span } => {
+                let vals = vals
+                    .iter()
+                    .map(|v| self.inc_recursive(head, v))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Value::Record {
+                    cols: cols.clone(),
+                    vals,
+                    span: *span,
+                })
+            }
+            leaf @ (Value::Int { .. } | Value::String { .. }) => {
+                let leaf_span = match leaf {
+                    Value::Int { span, .. } | Value::String { span, .. } => *span,
+                    _ => unreachable!(),
+                };
+                // propagate argument errors (bad phase move, bad --build) instead of swallowing them
+                self.inc_value(leaf_span, leaf)
+            }
+            other => Ok(other.clone()),
+        }
+    }
+
     pub fn inc_value(&self, head: Span, value: &Value) -> Result<Value, LabeledError> {
         match value {
             Value::Int { val, .. } => Ok(Value::int(val + 1, head)),
-            Value::String { val, .. } => Ok(self.apply(val, head)),
+            Value::String { val, .. } => self.apply(val, head),
             x => {
                 let msg = x.as_string().map_err(|e| LabeledError {
                     label: "Unable to extract string".into(),
@@ -148,7 +270,7 @@ mod tests {
             let expected = Value::test_string("1.0.0");
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Major);
-            assert_eq!(inc.apply("0.1.3", Span::test_data()), expected)
+            assert_eq!(inc.apply("0.1.3", Span::test_data()).unwrap(), expected)
         }
 
         #[test]
@@ -156,7 +278,7 @@ mod tests {
             let expected = Value::test_string("0.2.0");
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Minor);
-            assert_eq!(inc.apply("0.1.3", Span::test_data()), expected)
+            assert_eq!(inc.apply("0.1.3", Span::test_data()).unwrap(), expected)
         }
 
         #[test]
@@ -164,7 +286,249 @@ mod tests {
             let expected = Value::test_string("0.1.4");
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Patch);
-            assert_eq!(inc.apply("0.1.3", Span::test_data()), expected)
+            assert_eq!(inc.apply("0.1.3", Span::test_data()).unwrap(), expected)
+        }
+
+        #[test]
+        fn alpha_continues_existing_phase() {
+            let expected = Value::test_string("1.2.0-alpha.3");
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Alpha);
+            assert_eq!(
+                inc.apply("1.2.0-alpha.2", Span::test_data()).unwrap(),
+                expected
+            )
+        }
+
+        #[test]
+        fn beta_continues_existing_phase() {
+            let expected = Value::test_string("1.2.0-beta.3");
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Beta);
+            assert_eq!(
+                inc.apply("1.2.0-beta.2", Span::test_data()).unwrap(),
+                expected
+            )
+        }
+
+        #[test]
+        fn rc_continues_existing_phase() {
+            let expected = Value::test_string("1.2.0-rc.3");
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Rc);
+            assert_eq!(
+                inc.apply("1.2.0-rc.2", Span::test_data()).unwrap(),
+                expected
+            )
+        }
+
+        #[test]
+        fn beta_switches_from_earlier_phase() {
+            let expected = Value::test_string("1.2.0-beta.1");
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Beta);
+            assert_eq!(
+                inc.apply("1.2.0-alpha.2", Span::test_data()).unwrap(),
+                expected
+            )
+        }
+
+        #[test]
+        fn rc_from_release_bumps_patch_first() {
+            let expected = Value::test_string("1.2.1-rc.1");
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Rc);
+            assert_eq!(inc.apply("1.2.0", Span::test_data()).unwrap(), expected)
+        }
+
+        #[test]
+        fn alpha_cannot_move_backwards_from_rc() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Alpha);
+            assert!(inc.apply("1.2.0-rc.1", Span::test_data()).is_err())
+        }
+
+        #[test]
+        fn beta_cannot_move_backwards_from_beta_itself_via_alpha() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Alpha);
+            assert!(inc.apply("1.2.0-beta.1", Span::test_data()).is_err())
+        }
+
+        #[test]
+        fn build_is_attached_instead_of_cleared() {
+            let expected = Value::test_string("1.2.4+git.5114f85");
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.build = Some("git.5114f85".into());
+            assert_eq!(inc.apply("1.2.3", Span::test_data()).unwrap(), expected)
+        }
+
+        #[test]
+        fn invalid_build_metadata_is_an_error() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.build = Some("not valid!".into());
+            assert!(inc.apply("1.2.3", Span::test_data()).is_err())
+        }
+    }
+
+    mod parse {
+        use nu_protocol::{Span, Value};
+
+        use crate::Inc;
+
+        #[test]
+        fn full_version() {
+            let mut inc = Inc::new();
+            inc.for_parse();
+            let actual = inc.apply("1.2.3-beta.1+exp", Span::test_data()).unwrap();
+            assert_eq!(
+                actual,
+                Value::Record {
+                    cols: vec![
+                        "major".into(),
+                        "minor".into(),
+                        "patch".into(),
+                        "pre".into(),
+                        "build".into(),
+                    ],
+                    vals: vec![
+                        Value::test_int(1),
+                        Value::test_int(2),
+                        Value::test_int(3),
+                        Value::test_string("beta.1"),
+                        Value::test_string("exp"),
+                    ],
+                    span: Span::test_data(),
+                }
+            )
+        }
+
+        #[test]
+        fn version_without_pre_or_build() {
+            let mut inc = Inc::new();
+            inc.for_parse();
+            let actual = inc.apply("1.2.3", Span::test_data()).unwrap();
+            assert_eq!(
+                actual,
+                Value::Record {
+                    cols: vec![
+                        "major".into(),
+                        "minor".into(),
+                        "patch".into(),
+                        "pre".into(),
+                        "build".into(),
+                    ],
+                    vals: vec![
+                        Value::test_int(1),
+                        Value::test_int(2),
+                        Value::test_int(3),
+                        Value::test_string(""),
+                        Value::test_string(""),
+                    ],
+                    span: Span::test_data(),
+                }
+            )
+        }
+
+        #[test]
+        fn unparsable_version_is_returned_unchanged() {
+            let expected = Value::test_string("not a version");
+            let mut inc = Inc::new();
+            inc.for_parse();
+            assert_eq!(
+                inc.apply("not a version", Span::test_data()).unwrap(),
+                expected
+            )
+        }
+    }
+
+    mod recursive {
+        use nu_protocol::{Span, Value};
+
+        use crate::inc::SemVerAction;
+        use crate::Inc;
+
+        #[test]
+        fn increments_every_leaf_in_a_list() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.recursive = true;
+
+            let input = Value::List {
+                vals: vec![Value::test_string("1.0.0"), Value::test_string("2.3.4")],
+                span: Span::test_data(),
+            };
+
+            let actual = inc.inc(Span::test_data(), &input).unwrap();
+            assert_eq!(
+                actual,
+                Value::List {
+                    vals: vec![Value::test_string("1.0.1"), Value::test_string("2.3.5")],
+                    span: Span::test_data(),
+                }
+            )
+        }
+
+        #[test]
+        fn increments_every_leaf_in_a_record_and_preserves_mixed_leaves() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.recursive = true;
+
+            let input = Value::Record {
+                cols: vec!["version".into(), "name".into(), "count".into()],
+                vals: vec![
+                    Value::test_string("1.0.0"),
+                    Value::test_string("not-a-version"),
+                    Value::test_int(1),
+                ],
+                span: Span::test_data(),
+            };
+
+            let actual = inc.inc(Span::test_data(), &input).unwrap();
+            assert_eq!(
+                actual,
+                Value::Record {
+                    cols: vec!["version".into(), "name".into(), "count".into()],
+                    vals: vec![
+                        Value::test_string("1.0.1"),
+                        Value::test_string("not-a-version"),
+                        Value::test_int(2),
+                    ],
+                    span: Span::test_data(),
+                }
+            )
+        }
+
+        #[test]
+        fn backwards_phase_move_errors_instead_of_being_silently_dropped() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Alpha);
+            inc.recursive = true;
+
+            let input = Value::List {
+                vals: vec![Value::test_string("1.2.0-rc.1")],
+                span: Span::test_data(),
+            };
+
+            assert!(inc.inc(Span::test_data(), &input).is_err())
+        }
+
+        #[test]
+        fn invalid_build_errors_instead_of_being_silently_dropped() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.build = Some("not valid!".into());
+            inc.recursive = true;
+
+            let input = Value::List {
+                vals: vec![Value::test_string("1.2.3")],
+                span: Span::test_data(),
+            };
+
+            assert!(inc.inc(Span::test_data(), &input).is_err())
         }
     }
 }